@@ -4,6 +4,9 @@
 //! standard formatting traits and print the bytes as a hexadecimal string, eliding from the middle
 //! if the length would exceed the `precision` format parameter.
 //!
+//! The crate is `no_std` when built without its default `std` feature. Enabling `std` only links
+//! the standard library, which nothing in this crate currently requires.
+//!
 //! ```
 //! use hex_fmt::{HexFmt, HexList};
 //!
@@ -14,16 +17,124 @@
 //! assert_eq!("090a..0e0f", &format!("{}", HexFmt(&nine_to_f)));
 //! assert_eq!("[4142, 4241]", &format!("{}", HexList(&[b"AB", b"BA"])));
 //! assert_eq!("[4A4B, 4B4A]", &format!("{:X}", HexList(&[b"JK", b"KJ"])));
+//! assert_eq!("  090a0b", &format!("{:>8}", HexFmt(&[9u8, 10, 11])));
+//! assert_eq!("*090a0b*", &format!("{:*^8}", HexFmt(&[9u8, 10, 11])));
+//!
+//! use hex_fmt::Elide;
+//! assert_eq!("090a0b..", &format!("{:.8}", HexFmt::new(&nine_to_f).elide(Elide::End)));
+//! assert_eq!("..0d0e0f", &format!("{:.8}", HexFmt::new(&nine_to_f).elide(Elide::Start)));
+//! assert_eq!("090a0…0e0f", &format!("{}", HexFmt::new(&nine_to_f).ellipsis("…")));
 //! ```
 
-use std::fmt::{Debug, Display, Formatter, LowerHex, Result, UpperHex};
+// `cfg(test)` is included here (alongside `feature = "std"`) so that `cargo test` always links
+// `std`, even with `--no-default-features`: the test module below uses `format!`/`vec!`, which
+// need an explicit `std` to be in scope once the crate opts out of the prelude via `no_std`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+use core::fmt::{Alignment, Debug, Display, Formatter, LowerHex, Result, UpperHex, Write};
+use core::str;
 
 const DEFAULT_PRECISION: usize = 10;
 const ELLIPSIS: &str = "..";
+const DEFAULT_BYTES_PER_LINE: usize = 16;
+// `fmt_dump` pads every line out to `bytes_per_line` columns even on a short final chunk, so an
+// unbounded value would let a single `HexDump::bytes_per_line` call blow up the size of every
+// line it prints.
+const MAX_BYTES_PER_LINE: usize = 256;
+const BYTE_GROUP_SIZE: usize = 8;
+
+/// Size of the stack buffer used to batch hex digits before flushing them to the `Formatter` in
+/// a single `write_str` call.
+const BUF_LEN: usize = 64;
+
+const LOWER_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+const fn hex_table(digits: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut table = [[0u8; 2]; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        table[byte] = [digits[byte >> 4], digits[byte & 0x0f]];
+        byte += 1;
+    }
+    table
+}
+
+const LOWER_HEX_TABLE: [[u8; 2]; 256] = hex_table(LOWER_DIGITS);
+const UPPER_HEX_TABLE: [[u8; 2]; 256] = hex_table(UPPER_DIGITS);
+
+/// Where bytes are elided from when the hex representation would otherwise exceed the
+/// `precision` format parameter. See [`HexFmt::elide`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Elide {
+    /// Elide from the middle, e.g. `"090a..0e0f"`. This is the default.
+    Middle,
+    /// Elide the end, keeping the leading bytes, e.g. `"090a0b.."`.
+    End,
+    /// Elide the start, keeping the trailing bytes, e.g. `"..0e0f"`.
+    Start,
+}
 
 /// Wrapper for a byte array, whose `Debug`, `Display` and `LowerHex` implementations output
 /// shortened hexadecimal strings.
-pub struct HexFmt<T>(pub T);
+///
+/// The tuple constructor `HexFmt(data)` gives today's default behavior (eliding from the middle
+/// with a `".."` marker). Use [`HexFmt::new`] together with [`HexFmt::ellipsis`] and
+/// [`HexFmt::elide`] to configure the marker and where elision happens.
+///
+/// `HexFmt` used to be a public tuple struct (`pub struct HexFmt<T>(pub T)`), so code that
+/// pattern-matched or destructured it directly, e.g. `let HexFmt(data) = wrapped;`, no longer
+/// compiles: the wrapped value is a private field now. `HexFmt` derefs to the wrapped value, so
+/// `*wrapped` and method calls on `wrapped` through autoderef still work.
+pub struct HexFmt<T> {
+    data: T,
+    ellipsis: &'static str,
+    elide: Elide,
+}
+
+#[allow(non_snake_case)]
+/// Wraps `data` for hex formatting with today's default behavior. Equivalent to
+/// `HexFmt::new(data)`.
+pub fn HexFmt<T>(data: T) -> HexFmt<T> {
+    HexFmt::new(data)
+}
+
+impl<T> HexFmt<T> {
+    /// Wraps `data` for hex formatting, eliding from the middle with a `".."` marker by default.
+    pub fn new(data: T) -> Self {
+        HexFmt {
+            data,
+            ellipsis: ELLIPSIS,
+            elide: Elide::Middle,
+        }
+    }
+
+    /// Sets the marker printed in place of the elided bytes.
+    pub fn ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets where elision happens when the content exceeds `precision`.
+    pub fn elide(mut self, elide: Elide) -> Self {
+        self.elide = elide;
+        self
+    }
+}
+
+impl<T> core::ops::Deref for HexFmt<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+impl<T> core::ops::DerefMut for HexFmt<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+}
 
 impl<T: AsRef<[u8]>> Debug for HexFmt<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
@@ -39,13 +150,13 @@ impl<T: AsRef<[u8]>> Display for HexFmt<T> {
 
 impl<T: AsRef<[u8]>> LowerHex for HexFmt<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        fmt(self.0.as_ref(), f, Case::Lower)
+        fmt(self.data.as_ref(), f, Case::Lower, self.ellipsis, self.elide)
     }
 }
 
 impl<T: AsRef<[u8]>> UpperHex for HexFmt<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        fmt(self.0.as_ref(), f, Case::Upper)
+        fmt(self.data.as_ref(), f, Case::Upper, self.ellipsis, self.elide)
     }
 }
 
@@ -99,65 +210,332 @@ where
     }
 }
 
-fn fmt(bytes: &[u8], f: &mut Formatter, case: Case) -> Result {
-    // TODO: Respect `f.width()`, `f.align()` and `f.fill()`.
+/// Extension trait adding `as_hex` to any byte container, so it can be formatted as hex without
+/// wrapping it in [`HexFmt`] explicitly.
+///
+/// ```
+/// use hex_fmt::DisplayHex;
+///
+/// let data = vec![0x01u8, 0x23, 0x45, 0x67];
+/// assert_eq!("01..67", &format!("{:.6}", data.as_hex()));
+/// ```
+pub trait DisplayHex {
+    /// Wraps `self` for hex formatting, carrying through any width, precision, fill and align
+    /// parameters given to the returned value.
+    fn as_hex(&self) -> HexFmt<&[u8]>;
+}
+
+impl<T: AsRef<[u8]> + ?Sized> DisplayHex for T {
+    fn as_hex(&self) -> HexFmt<&[u8]> {
+        HexFmt(self.as_ref())
+    }
+}
+
+/// Extension trait adding `as_hex_list` to slices of byte containers, so they can be formatted
+/// as a hex list without wrapping them in [`HexList`] explicitly.
+///
+/// ```
+/// use hex_fmt::DisplayHexList;
+///
+/// let packets: Vec<Vec<u8>> = vec![vec![0x01, 0x23], vec![0x45, 0x67]];
+/// assert_eq!("[0123, 4567]", &format!("{}", packets.as_hex_list()));
+/// ```
+pub trait DisplayHexList<T> {
+    /// Wraps `self` for hex-list formatting.
+    fn as_hex_list(&self) -> HexList<&[T]>;
+}
+
+impl<T: AsRef<[u8]>> DisplayHexList<T> for [T] {
+    fn as_hex_list(&self) -> HexList<&[T]> {
+        HexList(self)
+    }
+}
+
+/// Wrapper for a byte slice whose `Debug`, `Display`, `LowerHex` and `UpperHex` implementations
+/// render a classic `hexdump -C`-style table: each line shows a zero-padded offset, the hex
+/// bytes (grouped in eights), and an ASCII gutter with non-printable bytes shown as `.`.
+///
+/// The number of bytes shown per line defaults to 16 and can be changed with
+/// [`HexDump::bytes_per_line`].
+///
+/// ```
+/// use hex_fmt::HexDump;
+///
+/// let bytes: Vec<u8> = (0..20).collect();
+/// println!("{}", HexDump::new(&bytes));
+/// // 00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f |................|
+/// // 00000010  10 11 12 13                                      |....|
+/// ```
+pub struct HexDump<T> {
+    data: T,
+    bytes_per_line: usize,
+}
+
+impl<T> HexDump<T> {
+    /// Wraps `data` for hex-dump formatting with the default of 16 bytes per line.
+    pub fn new(data: T) -> Self {
+        HexDump {
+            data,
+            bytes_per_line: DEFAULT_BYTES_PER_LINE,
+        }
+    }
+
+    /// Sets the number of bytes shown on each line, clamped to between 1 and 256: every line is
+    /// padded out to this width, so an unclamped value could make a single call print
+    /// arbitrarily large lines.
+    pub fn bytes_per_line(mut self, bytes_per_line: usize) -> Self {
+        self.bytes_per_line = bytes_per_line.clamp(1, MAX_BYTES_PER_LINE);
+        self
+    }
+}
+
+impl<T: AsRef<[u8]>> Debug for HexDump<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<T: AsRef<[u8]>> Display for HexDump<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        LowerHex::fmt(self, f)
+    }
+}
+
+impl<T: AsRef<[u8]>> LowerHex for HexDump<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_dump(self.data.as_ref(), f, Case::Lower, self.bytes_per_line)
+    }
+}
+
+impl<T: AsRef<[u8]>> UpperHex for HexDump<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        fmt_dump(self.data.as_ref(), f, Case::Upper, self.bytes_per_line)
+    }
+}
+
+fn fmt_dump(bytes: &[u8], f: &mut Formatter, case: Case, bytes_per_line: usize) -> Result {
+    for (line_idx, chunk) in bytes.chunks(bytes_per_line).enumerate() {
+        if line_idx > 0 {
+            f.write_char('\n')?;
+        }
+        fmt_offset(f, line_idx * bytes_per_line, case)?;
+        f.write_str("  ")?;
+        for i in 0..bytes_per_line {
+            if i > 0 && i % BYTE_GROUP_SIZE == 0 {
+                f.write_char(' ')?;
+            }
+            match chunk.get(i) {
+                Some(byte) => write_hex_byte(f, *byte, case)?,
+                None => f.write_str("  ")?,
+            }
+            f.write_char(' ')?;
+        }
+        f.write_char('|')?;
+        for byte in chunk {
+            let printable = matches!(byte, 0x20..=0x7e);
+            f.write_char(if printable { *byte as char } else { '.' })?;
+        }
+        f.write_char('|')?;
+    }
+    Ok(())
+}
+
+fn fmt_offset(f: &mut Formatter, offset: usize, case: Case) -> Result {
+    match case {
+        Case::Upper => write!(f, "{:08X}", offset),
+        Case::Lower => write!(f, "{:08x}", offset),
+    }
+}
+
+fn fmt(bytes: &[u8], f: &mut Formatter, case: Case, ellipsis: &str, elide: Elide) -> Result {
     let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
 
+    // The length of the rendered content is known up front, without actually writing it: it's
+    // the full hex string, unless that would exceed `precision`, in which case elision kicks in
+    // and the output is exactly `precision` characters long.
+    let len = (2 * bytes.len()).min(precision);
+    let width = f.width().unwrap_or(0);
+    if width <= len {
+        return fmt_content(bytes, f, precision, case, ellipsis, elide);
+    }
+
+    let fill = f.fill();
+    let total_pad = width - len;
+    let (left_pad, right_pad) = match f.align() {
+        Some(Alignment::Left) => (0, total_pad),
+        Some(Alignment::Center) => (total_pad / 2, total_pad - total_pad / 2),
+        Some(Alignment::Right) | None => (total_pad, 0),
+    };
+    for _ in 0..left_pad {
+        f.write_char(fill)?;
+    }
+    fmt_content(bytes, f, precision, case, ellipsis, elide)?;
+    for _ in 0..right_pad {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+fn fmt_content(
+    bytes: &[u8],
+    f: &mut Formatter,
+    precision: usize,
+    case: Case,
+    ellipsis: &str,
+    elide: Elide,
+) -> Result {
+    let mut buf = HexBuf::new(f);
+
     // If the array is short enough, don't shorten it.
     if 2 * bytes.len() <= precision {
         for byte in bytes {
-            fmt_byte(f, *byte, case)?;
+            buf.push_byte(*byte, case)?;
         }
-        return Ok(());
+        return buf.flush();
     }
 
+    // The marker's *display* width (in chars, not bytes) is what counts against `precision`, so
+    // that multi-byte markers like "…" are accounted for correctly.
+    let ellipsis_width = ellipsis.chars().count();
+
     // If the bytes don't fit and the ellipsis fills the maximum width, print only that.
-    if precision <= ELLIPSIS.len() {
-        return write!(f, "{:.*}", precision, ELLIPSIS);
+    if precision <= ellipsis_width {
+        buf.push_str(truncate_chars(ellipsis, precision))?;
+        return buf.flush();
     }
 
-    // Compute the number of hex digits to display left and right of the ellipsis.
-    let num_hex_digits = precision.saturating_sub(ELLIPSIS.len());
-    let right = num_hex_digits / 2;
-    let left = num_hex_digits - right;
+    // Compute the number of hex digits to display, then split them left and right of the
+    // ellipsis according to `elide`: `Middle` splits them evenly, while `Start`/`End` put them
+    // all on one side, collapsing the other side's loops below to no-ops.
+    let num_hex_digits = precision - ellipsis_width;
+    let (left, right) = match elide {
+        Elide::Middle => {
+            let right = num_hex_digits / 2;
+            (num_hex_digits - right, right)
+        }
+        Elide::Start => (0, num_hex_digits),
+        Elide::End => (num_hex_digits, 0),
+    };
 
     // Print the bytes on the left.
     for byte in &bytes[..(left / 2)] {
-        fmt_byte(f, *byte, case)?;
+        buf.push_byte(*byte, case)?;
     }
     // If odd, print only the first hex digit of the next byte.
     if left & 1 == 1 {
-        fmt_digit(f, bytes[left / 2] >> 4, case)?;
+        buf.push_digit(bytes[left / 2] >> 4, case)?;
     }
 
     // Print the ellipsis.
-    f.write_str(ELLIPSIS)?;
+    buf.push_str(ellipsis)?;
 
     // If `right` is odd, print the second hex digit of a byte.
     if right & 1 == 1 {
-        fmt_digit(f, bytes[(bytes.len() - right / 2 - 1)] & 0x0f, case)?;
+        buf.push_digit(bytes[bytes.len() - right / 2 - 1] & 0x0f, case)?;
     }
     // Print the remaining bytes on the right.
     for byte in &bytes[(bytes.len() - right / 2)..] {
-        fmt_byte(f, *byte, case)?;
+        buf.push_byte(*byte, case)?;
+    }
+    buf.flush()
+}
+
+/// Returns the prefix of `s` consisting of its first `n` characters.
+fn truncate_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
+
+/// Accumulates hex digits in a small stack buffer and flushes them to a `Formatter` in a single
+/// `write_str` call, rather than going through the formatting machinery for every byte.
+struct HexBuf<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    buf: [u8; BUF_LEN],
+    len: usize,
+}
+
+impl<'a, 'b> HexBuf<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>) -> Self {
+        HexBuf {
+            f,
+            buf: [0; BUF_LEN],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, byte: u8) -> Result {
+        if self.len == self.buf.len() {
+            self.flush()?;
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_byte(&mut self, byte: u8, case: Case) -> Result {
+        let digits = hex_digits(byte, case);
+        self.push(digits[0])?;
+        self.push(digits[1])
+    }
+
+    fn push_digit(&mut self, digit: u8, case: Case) -> Result {
+        self.push(hex_digit(digit, case))
+    }
+
+    fn push_str(&mut self, s: &str) -> Result {
+        // `push` flushes mid-string whenever the buffer fills up, which would split a
+        // multi-byte `char` (e.g. a custom ellipsis like "…") across two `write_str` calls and
+        // leave the buffer holding invalid UTF-8 at the point of the split. Flush first whenever
+        // `s` wouldn't fit in what's left, so a string's bytes always land in one contiguous
+        // piece of the buffer (or, if it's bigger than the whole buffer, are written directly).
+        if s.len() > self.buf.len() {
+            self.flush()?;
+            return self.f.write_str(s);
+        }
+        if self.len + s.len() > self.buf.len() {
+            self.flush()?;
+        }
+        for byte in s.bytes() {
+            self.push(byte)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result {
+        if self.len > 0 {
+            // Every flush point lands on a boundary between complete pushes of ASCII hex digits
+            // or whole marker strings (see `push_str`), so the buffer never holds a string split
+            // mid-character: it always holds valid UTF-8.
+            let s = unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) };
+            self.f.write_str(s)?;
+            self.len = 0;
+        }
+        Ok(())
     }
-    Ok(())
 }
 
-fn fmt_byte(f: &mut Formatter, byte: u8, case: Case) -> Result {
+fn hex_digits(byte: u8, case: Case) -> &'static [u8; 2] {
     match case {
-        Case::Upper => write!(f, "{:02X}", byte),
-        Case::Lower => write!(f, "{:02x}", byte),
+        Case::Upper => &UPPER_HEX_TABLE[byte as usize],
+        Case::Lower => &LOWER_HEX_TABLE[byte as usize],
     }
 }
 
-fn fmt_digit(f: &mut Formatter, digit: u8, case: Case) -> Result {
+fn hex_digit(digit: u8, case: Case) -> u8 {
     match case {
-        Case::Upper => write!(f, "{:1X}", digit),
-        Case::Lower => write!(f, "{:1x}", digit),
+        Case::Upper => UPPER_DIGITS[digit as usize],
+        Case::Lower => LOWER_DIGITS[digit as usize],
     }
 }
 
+fn write_hex_byte(f: &mut Formatter, byte: u8, case: Case) -> Result {
+    let digits = hex_digits(byte, case);
+    // `digits` is always two ASCII hex characters, hence always valid UTF-8.
+    f.write_str(unsafe { str::from_utf8_unchecked(digits) })
+}
+
 #[derive(Copy, Clone)]
 enum Case {
     Upper,
@@ -166,7 +544,7 @@ enum Case {
 
 #[cfg(test)]
 mod tests {
-    use super::HexFmt;
+    use super::{DisplayHex, DisplayHexList, Elide, HexDump, HexFmt, MAX_BYTES_PER_LINE};
 
     #[test]
     fn test_fmt() {
@@ -175,4 +553,127 @@ mod tests {
         assert_eq!("01", &format!("{:.2}", HexFmt(&[0x01])));
         assert_eq!("..", &format!("{:.2}", HexFmt(&[0x01, 0x23])));
     }
+
+    #[test]
+    fn test_fmt_width() {
+        let bytes = [0x01u8, 0x23, 0x45];
+        assert_eq!("  012345", &format!("{:8}", HexFmt(&bytes)));
+        assert_eq!("012345  ", &format!("{:<8}", HexFmt(&bytes)));
+        assert_eq!(" 012345 ", &format!("{:^8}", HexFmt(&bytes)));
+        assert_eq!("**012345", &format!("{:*>8}", HexFmt(&bytes)));
+        assert_eq!("012345**", &format!("{:*<8}", HexFmt(&bytes)));
+        assert_eq!("*012345*", &format!("{:*^8}", HexFmt(&bytes)));
+        assert_eq!("012345", &format!("{:4}", HexFmt(&bytes)));
+    }
+
+    #[test]
+    fn test_fmt_long() {
+        let bytes: Vec<u8> = (0..40).collect();
+        let expected: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(expected, format!("{:.80}", HexFmt(&bytes)));
+    }
+
+    #[test]
+    fn test_hex_dump() {
+        let bytes: Vec<u8> = (0..20).collect();
+        let expected = "00000000  00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f \
+                         |................|\n\
+                         00000010  10 11 12 13                                      |....|";
+        assert_eq!(expected, &format!("{}", HexDump::new(&bytes)));
+    }
+
+    #[test]
+    fn test_hex_dump_bytes_per_line() {
+        let bytes = b"Hello!";
+        assert_eq!(
+            "00000000  48 65 6c |Hel|\n00000003  6c 6f 21 |lo!|",
+            &format!("{}", HexDump::new(bytes).bytes_per_line(3))
+        );
+    }
+
+    #[test]
+    fn test_hex_dump_bytes_per_line_is_clamped() {
+        let bytes = b"Hello!";
+        assert_eq!(
+            format!("{}", HexDump::new(bytes).bytes_per_line(MAX_BYTES_PER_LINE)),
+            format!("{}", HexDump::new(bytes).bytes_per_line(usize::MAX))
+        );
+    }
+
+    #[test]
+    fn test_as_hex() {
+        let bytes = vec![0x01u8, 0x23, 0x45, 0x67];
+        assert_eq!("01234567", &format!("{}", bytes.as_hex()));
+        assert_eq!("01..67", &format!("{:.6}", bytes.as_hex()));
+    }
+
+    #[test]
+    fn test_as_hex_list() {
+        let packets: Vec<Vec<u8>> = vec![vec![0x01, 0x23], vec![0x45, 0x67]];
+        assert_eq!("[0123, 4567]", &format!("{}", packets.as_hex_list()));
+        assert_eq!("[0123, 4567]", &format!("{:x}", packets.as_hex_list()));
+    }
+
+    #[test]
+    fn test_elide_end() {
+        let nine_to_f = [9u8, 10, 11, 12, 13, 14, 15];
+        assert_eq!(
+            "090a0b..",
+            &format!("{:.8}", HexFmt::new(&nine_to_f).elide(Elide::End))
+        );
+        assert_eq!(
+            "090a0b0..",
+            &format!("{:.9}", HexFmt::new(&nine_to_f).elide(Elide::End))
+        );
+    }
+
+    #[test]
+    fn test_elide_start() {
+        let nine_to_f = [9u8, 10, 11, 12, 13, 14, 15];
+        assert_eq!(
+            "..0d0e0f",
+            &format!("{:.8}", HexFmt::new(&nine_to_f).elide(Elide::Start))
+        );
+    }
+
+    #[test]
+    fn test_custom_ellipsis() {
+        let nine_to_f = [9u8, 10, 11, 12, 13, 14, 15];
+        assert_eq!(
+            "090a0…0e0f",
+            &format!("{}", HexFmt::new(&nine_to_f).ellipsis("…"))
+        );
+        assert_eq!(
+            "090a0b0c0d0e0f",
+            &format!("{:.20}", HexFmt::new(&nine_to_f).ellipsis("…"))
+        );
+    }
+
+    #[test]
+    fn test_new_matches_tuple_constructor_default() {
+        let bytes = [0x01u8, 0x23, 0x45];
+        assert_eq!(
+            format!("{}", HexFmt(&bytes)),
+            format!("{}", HexFmt::new(&bytes))
+        );
+    }
+
+    #[test]
+    fn test_deref() {
+        let bytes = [0x01u8, 0x23, 0x45];
+        let wrapped = HexFmt::new(&bytes);
+        assert_eq!(&bytes, *wrapped);
+        assert_eq!(3, wrapped.len());
+    }
+
+    #[test]
+    fn test_multi_byte_ellipsis_across_buffer_boundary() {
+        // Regression test: with this precision and a multi-byte marker, the left half of the
+        // content fills the internal buffer to one byte short of its flush point, which used to
+        // split the marker's UTF-8 bytes across two flushes.
+        let bytes: Vec<u8> = (0u8..200).collect();
+        let formatted = format!("{:.126}", HexFmt::new(&bytes).ellipsis("…"));
+        assert_eq!(126, formatted.chars().count());
+        assert!(formatted.contains('…'));
+    }
 }